@@ -1,6 +1,10 @@
+use std::collections::HashSet;
+use std::fmt;
 use std::mem;
 
 use fixedbitset::FixedBitSet;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use wasm_bindgen::prelude::*;
 
 use crate::utils::Timer;
@@ -13,6 +17,11 @@ mod utils;
 #[global_allocator]
 static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
 
+// Standard Conway rules (B3/S23): a dead cell is born with exactly 3 live
+// neighbors, a live cell survives with 2 or 3.
+const DEFAULT_BIRTH: u16 = 1 << 3;
+const DEFAULT_SURVIVAL: u16 = (1 << 2) | (1 << 3);
+
 /// The `Universe` struct represents the state of the Game of Life simulation.
 /// It contains the width and height of the grid, as well as the current and next
 /// generations of cells.
@@ -23,6 +32,16 @@ pub struct Universe {
     cells: FixedBitSet,
     // buffering the next generation for cells
     next_cells: FixedBitSet,
+    // bit `n` set means a dead cell with `n` live neighbors is born
+    birth: u16,
+    // bit `n` set means a live cell with `n` live neighbors survives
+    survival: u16,
+    // the seed used to generate the current cells, if any
+    seed: Option<u64>,
+    // whether `tick` only evaluates `active_cells` instead of scanning the whole grid
+    incremental: bool,
+    // indices that changed last tick plus their neighbors; the only cells that can change next
+    active_cells: HashSet<usize>,
 }
 
 impl Default for Universe {
@@ -31,6 +50,25 @@ impl Default for Universe {
     }
 }
 
+/// Renders the grid as one line per row, using `◼` for alive cells and `◻` for dead ones, in
+/// row-major order. This is the inverse of [`Universe::load_string`].
+impl fmt::Display for Universe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let index = self.get_index(row, col);
+                let symbol = if self.cells[index] { '◼' } else { '◻' };
+
+                write!(f, "{}", symbol)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[wasm_bindgen]
 impl Universe {
     /// Creates a new `Universe` instance with a default width and height of 128.
@@ -61,9 +99,29 @@ impl Universe {
             height,
             cells,
             next_cells,
+            birth: DEFAULT_BIRTH,
+            survival: DEFAULT_SURVIVAL,
+            seed: None,
+            incremental: false,
+            active_cells: HashSet::new(),
         }
     }
 
+    /// Creates a new `Universe` with the given width and height, deterministically seeded.
+    ///
+    /// The cells are populated with a `ChaCha8Rng` seeded from `seed`, so the same seed always
+    /// produces the same starting pattern. This makes golden-state tests and shareable "seed
+    /// codes" possible, unlike [`Universe::reset`] which uses `Math::random()`.
+    pub fn new_with_seed(width: u32, height: u32, seed: u64) -> Universe {
+        let mut universe = Universe::new();
+
+        universe.set_width(width);
+        universe.set_height(height);
+        universe.reset_with_seed(seed);
+
+        universe
+    }
+
     /// Resets the state of the `Universe` by randomly setting each cell to either alive or dead.
     ///
     /// This function iterates over all the cells in the `Universe` and randomly sets each cell to
@@ -74,6 +132,32 @@ impl Universe {
         for i in 0..size {
             self.cells.set(i, js_sys::Math::random() < 0.5);
         }
+
+        self.seed = None;
+
+        if self.incremental {
+            self.rebuild_active_cells();
+        }
+    }
+
+    /// Resets the state of the `Universe` using a deterministic, seeded random fill.
+    ///
+    /// Each cell is set to alive or dead with 50% probability, drawn from a `ChaCha8Rng`
+    /// seeded with `seed`. The same seed always reproduces the same pattern, and the seed is
+    /// stored so the universe can be regenerated later.
+    pub fn reset_with_seed(&mut self, seed: u64) {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let size = (self.width * self.height) as usize;
+
+        for i in 0..size {
+            self.cells.set(i, rng.gen_bool(0.5));
+        }
+
+        self.seed = Some(seed);
+
+        if self.incremental {
+            self.rebuild_active_cells();
+        }
     }
 
     /// Clears all cells in the `Universe` to the dead state.
@@ -81,6 +165,8 @@ impl Universe {
     /// This function iterates over all the cells in the `Universe` and sets each cell to the dead state.
     pub fn clear(&mut self) {
         self.cells.clear();
+        self.seed = None;
+        self.active_cells.clear();
     }
 
     /// Returns the width of the `Universe`.
@@ -128,7 +214,7 @@ impl Universe {
     /// Updates the size of the `cells` and `next_cells` fields to match the current `width` and `height` of the `Universe`.
     ///
     /// If the current size of `cells` is larger than the required size, the `cells` field is set to a new `FixedBitSet` with the required capacity.
-    /// Otherwise, the `cells` field is grown to the required size.
+    /// Otherwise, the `cells` field is grown to the required size. `next_cells` is kept the same size as `cells`, since `tick` relies on both buffers matching.
     fn update_cells_size(&mut self) {
         let size = (self.width * self.height) as usize;
 
@@ -138,6 +224,65 @@ impl Universe {
             // self.cells.clear();
             self.cells.grow(size);
         }
+
+        self.next_cells = FixedBitSet::with_capacity(size);
+        self.seed = None;
+
+        if self.incremental {
+            self.rebuild_active_cells();
+        }
+    }
+
+    /// Sets the birth/survival rule directly from neighbor-count bit masks.
+    ///
+    /// Bit `n` of `birth` being set means a dead cell with exactly `n` live neighbors is born.
+    /// Bit `n` of `survival` being set means a live cell with exactly `n` live neighbors survives.
+    /// Only bits 0 through 8 are meaningful, since a cell has at most 8 neighbors.
+    ///
+    /// Rules with bit 0 of `birth` set (spontaneous generation on 0 live neighbors) are
+    /// incompatible with [`Universe::set_incremental`]: every isolated dead cell would be born
+    /// every tick, but incremental mode's frontier never visits cells outside the neighborhood
+    /// of a recent change, so it would silently diverge from the full scan. Setting such a rule
+    /// turns incremental mode back off if it was on.
+    ///
+    /// # Arguments
+    /// * `birth` - The neighbor-count mask for births.
+    /// * `survival` - The neighbor-count mask for survivals.
+    pub fn set_rule(&mut self, birth: u16, survival: u16) {
+        self.birth = birth;
+        self.survival = survival;
+
+        if self.incremental && self.birth & 1 != 0 {
+            self.incremental = false;
+        } else if self.incremental {
+            // Cells that were stable under the old rule may change under the new one.
+            self.rebuild_active_cells();
+        }
+    }
+
+    /// Sets the birth/survival rule from standard `B.../S...` notation, e.g. `"B3/S23"` for
+    /// the default Game of Life rule, `"B36/S23"` for HighLife, or `"B2/S"` for Seeds.
+    ///
+    /// The digits after `B` and `S` are the neighbor counts (0-8) that trigger a birth or a
+    /// survival, respectively. Returns an error if the string does not contain exactly one
+    /// `B` part and one `S` part, or contains a digit outside 0-8.
+    ///
+    /// As with [`Universe::set_rule`], a rule whose `birth` mask includes 0 (e.g. `"B0/S..."`)
+    /// turns incremental mode back off if it was on, since that mode can't track spontaneous
+    /// generation on isolated dead cells.
+    pub fn set_rule_string(&mut self, rule: &str) -> Result<(), JsValue> {
+        let (birth, survival) = parse_rule_string(rule)?;
+
+        self.birth = birth;
+        self.survival = survival;
+
+        if self.incremental && self.birth & 1 != 0 {
+            self.incremental = false;
+        } else if self.incremental {
+            self.rebuild_active_cells();
+        }
+
+        Ok(())
     }
 
     /// Returns a raw pointer to the underlying `cells` bit set.
@@ -166,28 +311,240 @@ impl Universe {
         let index = self.get_index(row, col);
 
         self.cells.toggle(index);
+        self.seed = None;
+
+        if self.incremental {
+            self.activate(row, col);
+        }
     }
 
-    /// Advances the state of the `Universe` by one time step.
+    /// Renders the grid as a string, one line per row, using `◼`/`◻` glyphs per cell.
     ///
-    /// This function updates the state of the `Universe` by applying the rules of the Game of Life to each cell in the `cells` bit set. 
-    /// The new state is stored in the `next_cells` bit set, 
-    /// and then the `cells` and `next_cells` bit sets are swapped to make the new state the current state.
+    /// This lets callers dump frames to a terminal or a `<pre>` element without touching the
+    /// raw `cells()` pointer. See the `Display` impl for the exact format.
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    /// Loads a pattern in the de-facto Game of Life RLE format at `(0, 0)`. See
+    /// [`Universe::load_rle_with_offset`] for the format and placement details.
+    pub fn load_rle(&mut self, rle: &str) -> Result<(), JsValue> {
+        self.load_rle_with_offset(rle, 0, 0)
+    }
+
+    /// Loads a pattern in the de-facto Game of Life RLE format, placing it at
+    /// `(offset_row, offset_col)` in the current grid.
     ///
-    /// The function first iterates over all the cells in the `cells` bit set, 
-    /// calculating the number of live neighbors for each cell. 
-    /// It then updates the state of each cell in the `next_cells` bit set based on the number of live neighbors, 
-    /// following the rules of the Game of Life:
+    /// The header line `x = W, y = H` (optionally followed by `, rule = B.../S...`) declares the
+    /// pattern's bounding box; the universe is grown to fit if `offset + (W, H)` exceeds the
+    /// current size. The declared bounding box is cleared before placement, so this always
+    /// places the pattern rather than OR-ing it onto whatever was already there. The body is a
+    /// run-length encoded stream where `<count>b` is that many dead cells, `<count>o` that many
+    /// live cells, `$` ends a row, and `!` ends the pattern. If the header carries a rule
+    /// string, it is applied via [`Universe::set_rule_string`]. Returns an error instead of
+    /// panicking if a run's actual extent overruns the header's declared bounding box.
+    pub fn load_rle_with_offset(
+        &mut self,
+        rle: &str,
+        offset_row: u32,
+        offset_col: u32,
+    ) -> Result<(), JsValue> {
+        let (width, height, rule) = parse_rle_header(rle)?;
+
+        if let Some(rule) = rule {
+            self.set_rule_string(&rule)?;
+        }
+
+        let required_width = offset_col + width;
+        let required_height = offset_row + height;
+
+        if required_width > self.width {
+            self.set_width(required_width);
+        }
+
+        if required_height > self.height {
+            self.set_height(required_height);
+        }
+
+        // Place onto a clean slate: only the declared bounding box is touched, so a pattern
+        // loaded over an already-populated grid (mid-simulation, or a previous `load_rle`)
+        // replaces whatever was there instead of being OR'd onto it.
+        for r in 0..height {
+            for c in 0..width {
+                let index = self.get_index(offset_row + r, offset_col + c);
+
+                self.cells.set(index, false);
+            }
+        }
+
+        let body = rle
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('#') && !line.contains("x ="))
+            .collect::<Vec<_>>()
+            .join("");
+
+        let mut row = 0u32;
+        let mut col = 0u32;
+        let mut count = String::new();
+
+        for ch in body.chars() {
+            match ch {
+                '0'..='9' => count.push(ch),
+                'b' | 'o' => {
+                    let run = mem::take(&mut count).parse().unwrap_or(1);
+
+                    if row >= height || col + run > width {
+                        return Err(JsValue::from_str(&format!(
+                            "invalid RLE: run of {} '{}' at row {}, col {} overruns the declared {}x{} bounds",
+                            run, ch, row, col, width, height
+                        )));
+                    }
+
+                    if ch == 'o' {
+                        for i in 0..run {
+                            let index = self.get_index(offset_row + row, offset_col + col + i);
+
+                            self.cells.set(index, true);
+                        }
+                    }
+
+                    col += run;
+                }
+                '$' => {
+                    let run = mem::take(&mut count).parse().unwrap_or(1);
+
+                    row += run;
+                    col = 0;
+                }
+                '!' => break,
+                _ => {}
+            }
+        }
+
+        self.seed = None;
+
+        if self.incremental {
+            self.rebuild_active_cells();
+        }
+
+        Ok(())
+    }
+
+    /// Exports the current grid as a de-facto Game of Life RLE string, including the current
+    /// birth/survival rule in the header. The inverse of [`Universe::load_rle`].
+    pub fn to_rle(&self) -> String {
+        let mut rle = format!(
+            "x = {}, y = {}, rule = {}\n",
+            self.width,
+            self.height,
+            rule_to_string(self.birth, self.survival)
+        );
+
+        let mut line_len = 0;
+
+        for row in 0..self.height {
+            let mut col = 0;
+
+            while col < self.width {
+                let alive = self.cells[self.get_index(row, col)];
+                let run_start = col;
+
+                while col < self.width && self.cells[self.get_index(row, col)] == alive {
+                    col += 1;
+                }
+
+                let run = col - run_start;
+                let symbol = if alive { 'o' } else { 'b' };
+                let chunk = if run > 1 {
+                    format!("{}{}", run, symbol)
+                } else {
+                    symbol.to_string()
+                };
+
+                line_len += chunk.len();
+                rle.push_str(&chunk);
+            }
+
+            if row + 1 < self.height {
+                rle.push('$');
+                line_len += 1;
+            }
+
+            if line_len > 70 {
+                rle.push('\n');
+                line_len = 0;
+            }
+        }
+
+        rle.push('!');
+
+        rle
+    }
+
+    /// Returns whether incremental tick mode is currently enabled.
+    ///
+    /// This function returns the current value of the `incremental` flag. Useful after calling
+    /// [`Universe::set_incremental`], since that setter can silently refuse to enable (or turn
+    /// back off) incremental mode for a rule with spontaneous generation.
+    pub fn incremental(&self) -> bool {
+        self.incremental
+    }
+
+    /// Toggles incremental tick mode.
+    ///
+    /// When enabled, `tick` only re-evaluates cells in `active_cells` (cells that changed last
+    /// tick plus their eight neighbors) instead of scanning the whole grid, which is much
+    /// cheaper for sparse patterns on a large board. Dense, mostly-random boards don't benefit
+    /// and pay extra bookkeeping overhead, so this defaults to off. Enabling it rebuilds
+    /// `active_cells` from the currently alive cells.
+    ///
+    /// Refuses to enable if the current `birth` rule includes 0 (spontaneous generation on
+    /// isolated dead cells, e.g. `"B0/..."`), since the incremental frontier never visits cells
+    /// outside the neighborhood of a recent change and would silently diverge from a full scan
+    /// under such a rule. See [`Universe::set_rule`] and [`Universe::set_rule_string`].
+    ///
+    /// Because that guard can silently refuse to enable incremental mode (or turn it back off
+    /// when an incompatible rule is set), check [`Universe::incremental`] after calling this if
+    /// the caller needs to know whether the mode actually took effect.
+    pub fn set_incremental(&mut self, enabled: bool) {
+        if enabled && self.birth & 1 != 0 {
+            return;
+        }
+
+        self.incremental = enabled;
+
+        if enabled {
+            self.rebuild_active_cells();
+        }
+    }
+
+    /// Advances the state of the `Universe` by one time step.
     ///
-    /// - Any live cell with fewer than two live neighbors dies, as if caused by underpopulation.
-    /// - Any live cell with two or three live neighbors lives on to the next generation.
-    /// - Any live cell with more than three live neighbors dies, as if by overpopulation.
-    /// - Any dead cell with exactly three live neighbors becomes a live cell, as if by reproduction.
+    /// This function updates the state of the `Universe` by applying the rules of the Game of Life to each cell in the `cells` bit set.
+    /// The new state is stored in the `next_cells` bit set,
+    /// and then the `cells` and `next_cells` bit sets are swapped to make the new state the current state.
     ///
-    /// After updating the `next_cells` bit set, the function swaps the `cells` and `next_cells` bit sets to make the new state the current state.
+    /// When [`Universe::set_incremental`] is enabled, only `active_cells` are re-evaluated;
+    /// otherwise every cell in the grid is. Either way, each evaluated cell follows the current
+    /// `birth`/`survival` rule: a dead cell is born if bit `live_neighbors` is set in `birth`,
+    /// and a live cell survives if bit `live_neighbors` is set in `survival`. This defaults to
+    /// the standard Game of Life rule (B3/S23) but can be changed with [`Universe::set_rule`]
+    /// or [`Universe::set_rule_string`].
     pub fn tick(&mut self) {
         Timer::new("Universe::tick");
 
+        self.seed = None;
+
+        if self.incremental {
+            self.tick_incremental();
+        } else {
+            self.tick_full();
+        }
+    }
+
+    /// Re-evaluates every cell in the grid, as [`Universe::tick`] does when incremental mode
+    /// is off.
+    fn tick_full(&mut self) {
         let size = (self.width * self.height) as usize;
         for i in 0..size {
             let row = (i as u32) / self.width;
@@ -195,22 +552,85 @@ impl Universe {
             let cell = self.cells[i];
             let live_neighbors = self.live_neighbor_count(row, col);
 
-            self.next_cells.set(
-                i,
-                match (cell, live_neighbors) {
-                    (true, x) if x < 2 => false,
-                    (true, 2) | (true, 3) => true,
-                    (true, x) if x > 3 => false,
-                    (false, 3) => true,
-                    (otherwise, _) => otherwise
-                }
-            );
+            let alive = if cell {
+                self.survival & (1 << live_neighbors) != 0
+            } else {
+                self.birth & (1 << live_neighbors) != 0
+            };
+
+            self.next_cells.set(i, alive);
         }
 
         // Swap current cells with next cells
         mem::swap(&mut self.cells, &mut self.next_cells);
     }
 
+    /// Re-evaluates only `active_cells`, as [`Universe::tick`] does when incremental mode is
+    /// on. No cell outside the frontier of "changed last tick plus its neighbors" can possibly
+    /// change, so this skips the neighbor-counting work for the rest of the grid. Afterwards,
+    /// `active_cells` is rebuilt from the cells that actually flipped and their neighborhoods,
+    /// respecting toroidal wrapping.
+    fn tick_incremental(&mut self) {
+        self.next_cells.clone_from(&self.cells);
+
+        let mut changed = Vec::new();
+
+        for &i in &self.active_cells {
+            let row = (i as u32) / self.width;
+            let col = (i as u32) % self.width;
+            let cell = self.cells[i];
+            let live_neighbors = self.live_neighbor_count(row, col);
+
+            let alive = if cell {
+                self.survival & (1 << live_neighbors) != 0
+            } else {
+                self.birth & (1 << live_neighbors) != 0
+            };
+
+            self.next_cells.set(i, alive);
+
+            if alive != cell {
+                changed.push((row, col));
+            }
+        }
+
+        mem::swap(&mut self.cells, &mut self.next_cells);
+
+        let mut next_active = HashSet::with_capacity(changed.len() * 9);
+
+        for (row, col) in changed {
+            next_active.insert(self.get_index(row, col));
+            next_active.extend(self.neighbor_indices(row, col));
+        }
+
+        self.active_cells = next_active;
+    }
+
+    /// Marks the cell at `(row, col)` and its eight neighbors as active, so the next
+    /// incremental tick re-evaluates them.
+    fn activate(&mut self, row: u32, col: u32) {
+        self.active_cells.insert(self.get_index(row, col));
+        self.active_cells.extend(self.neighbor_indices(row, col));
+    }
+
+    /// Rebuilds `active_cells` from scratch: every currently alive cell plus its neighbors.
+    fn rebuild_active_cells(&mut self) {
+        let size = (self.width * self.height) as usize;
+        let mut active = HashSet::new();
+
+        for i in 0..size {
+            if self.cells[i] {
+                let row = (i as u32) / self.width;
+                let col = (i as u32) % self.width;
+
+                active.insert(i);
+                active.extend(self.neighbor_indices(row, col));
+            }
+        }
+
+        self.active_cells = active;
+    }
+
     /// Calculates the index of a cell in the `cells` bit set given its row and column coordinates.
     ///
     /// This function takes the row and column coordinates of a cell and calculates the corresponding index in the `cells` bit set.
@@ -230,9 +650,8 @@ impl Universe {
     /// Calculates the number of live neighbors for a given cell in the Game of Life.
     ///
     /// This function takes the row and column coordinates of a cell and calculates the number of live neighbors
-    /// surrounding that cell. It does this by checking the eight adjacent cells and counting how many of them
-    /// are alive. The function handles the edge cases where the cell is on the edge of the grid by wrapping
-    /// around to the opposite side of the grid.
+    /// surrounding that cell, by checking the eight adjacent cells returned by [`Universe::neighbor_indices`]
+    /// and counting how many of them are alive.
     ///
     /// # Arguments
     /// * `row` - The row coordinate of the cell.
@@ -241,10 +660,17 @@ impl Universe {
     /// # Returns
     /// The number of live neighbors for the given cell.
     fn live_neighbor_count(&self, row: u32, column: u32) -> u8 {
-        let mut count = 0;
+        self.neighbor_indices(row, column)
+            .iter()
+            .map(|&i| self.cells[i] as u8)
+            .sum()
+    }
 
+    /// Returns the indices of the eight cells surrounding `(row, column)`, wrapping around the
+    /// edges of the grid (toroidal topology). Shared by [`Universe::live_neighbor_count`] and
+    /// the incremental-tick frontier bookkeeping.
+    fn neighbor_indices(&self, row: u32, column: u32) -> [usize; 8] {
         let north = if row == 0 { self.height - 1 } else { row - 1 };
-
         let south = if row == self.height - 1 { 0 } else { row + 1 };
 
         let west = if column == 0 {
@@ -259,31 +685,16 @@ impl Universe {
             column + 1
         };
 
-        let nw = self.get_index(north, west);
-        count += self.cells[nw] as u8;
-
-        let n = self.get_index(north, column);
-        count += self.cells[n] as u8;
-
-        let ne = self.get_index(north, east);
-        count += self.cells[ne] as u8;
-
-        let w = self.get_index(row, west);
-        count += self.cells[w] as u8;
-
-        let e = self.get_index(row, east);
-        count += self.cells[e] as u8;
-
-        let sw = self.get_index(south, west);
-        count += self.cells[sw] as u8;
-
-        let s = self.get_index(south, column);
-        count += self.cells[s] as u8;
-
-        let se = self.get_index(south, east);
-        count += self.cells[se] as u8;
-
-        count
+        [
+            self.get_index(north, west),
+            self.get_index(north, column),
+            self.get_index(north, east),
+            self.get_index(row, west),
+            self.get_index(row, east),
+            self.get_index(south, west),
+            self.get_index(south, column),
+            self.get_index(south, east),
+        ]
     }
 }
 
@@ -294,6 +705,15 @@ impl Universe {
         &self.cells
     }
 
+    /// Returns the seed used to generate the current cells, or `None` if the cells were
+    /// generated with `Math::random()`, or have been mutated since the last seeded reset by
+    /// [`Universe::toggle_cell`], [`Universe::set_alive_cells`], [`Universe::load_string`], or
+    /// [`Universe::load_rle_with_offset`] (which every cell-mutating method invalidates the
+    /// seed through).
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
     /// Sets the alive cells in the Universe to the given list of cell coordinates.
     ///
     /// This function takes a slice of `(u32, u32)` tuples, where each tuple represents the row and column
@@ -312,6 +732,134 @@ impl Universe {
             let index = self.get_index(*row, *col);
 
             self.cells.set(index, true);
-        })
+        });
+
+        self.seed = None;
+
+        if self.incremental {
+            self.rebuild_active_cells();
+        }
     }
+
+    /// Creates a new `Universe` with the given width and height from a `◼`/`◻` glyph grid,
+    /// as produced by [`Universe::render`] / the `Display` impl.
+    pub fn from_string(width: u32, height: u32, pattern: &str) -> Universe {
+        let mut universe = Universe::new();
+
+        universe.set_width(width);
+        universe.set_height(height);
+        universe.load_string(pattern);
+
+        universe
+    }
+
+    /// Loads a `◼`/`◻` glyph grid into the current cells, one line per row in row-major order.
+    /// Cells outside the current width/height are ignored. Pairs naturally with
+    /// [`Universe::set_alive_cells`] as an alternative way to seed a pattern.
+    pub fn load_string(&mut self, pattern: &str) {
+        self.cells.clear();
+
+        for (row, line) in pattern.lines().enumerate() {
+            for (col, symbol) in line.chars().enumerate() {
+                if symbol == '◼' && (row as u32) < self.height && (col as u32) < self.width {
+                    let index = self.get_index(row as u32, col as u32);
+
+                    self.cells.set(index, true);
+                }
+            }
+        }
+
+        self.seed = None;
+
+        if self.incremental {
+            self.rebuild_active_cells();
+        }
+    }
+}
+
+/// Parses standard `B.../S...` rule notation (e.g. `"B3/S23"`) into `(birth, survival)`
+/// neighbor-count bit masks, as used by [`Universe::set_rule_string`].
+fn parse_rule_string(rule: &str) -> Result<(u16, u16), JsValue> {
+    let mut birth = None;
+    let mut survival = None;
+
+    for part in rule.split('/') {
+        let mut chars = part.trim().chars();
+
+        match chars.next() {
+            Some('B') | Some('b') if birth.is_none() => {
+                birth = Some(parse_neighbor_mask(chars.as_str(), rule)?)
+            }
+            Some('S') | Some('s') if survival.is_none() => {
+                survival = Some(parse_neighbor_mask(chars.as_str(), rule)?)
+            }
+            _ => return Err(JsValue::from_str(&format!("invalid rule string: {}", rule))),
+        }
+    }
+
+    match (birth, survival) {
+        (Some(birth), Some(survival)) => Ok((birth, survival)),
+        _ => Err(JsValue::from_str(&format!("invalid rule string: {}", rule))),
+    }
+}
+
+/// Parses the neighbor-count digits following a `B` or `S` in rule notation into a bit mask.
+fn parse_neighbor_mask(digits: &str, rule: &str) -> Result<u16, JsValue> {
+    let mut mask = 0u16;
+
+    for digit in digits.chars() {
+        let n = digit
+            .to_digit(10)
+            .filter(|n| *n <= 8)
+            .ok_or_else(|| JsValue::from_str(&format!("invalid rule string: {}", rule)))?;
+
+        mask |= 1 << n;
+    }
+
+    Ok(mask)
+}
+
+/// Parses an RLE header line (`x = W, y = H, rule = B.../S...`) into its width, height, and
+/// optional rule string, as used by [`Universe::load_rle_with_offset`]. Comment lines starting
+/// with `#` are skipped.
+fn parse_rle_header(rle: &str) -> Result<(u32, u32, Option<String>), JsValue> {
+    let header = rle
+        .lines()
+        .find(|line| !line.trim_start().starts_with('#') && line.contains("x ="))
+        .ok_or_else(|| JsValue::from_str("invalid RLE: missing header line"))?;
+
+    let mut width = None;
+    let mut height = None;
+    let mut rule = None;
+
+    for field in header.split(',') {
+        let mut parts = field.splitn(2, '=');
+        let key = parts.next().unwrap_or_default().trim();
+        let value = parts.next().unwrap_or_default().trim();
+
+        match key {
+            "x" => width = value.parse().ok(),
+            "y" => height = value.parse().ok(),
+            "rule" => rule = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    match (width, height) {
+        (Some(width), Some(height)) => Ok((width, height, rule)),
+        _ => Err(JsValue::from_str("invalid RLE: missing x/y dimensions")),
+    }
+}
+
+/// Formats a birth/survival neighbor-count bit mask pair as `B.../S...` rule notation, the
+/// inverse of [`parse_rule_string`].
+fn rule_to_string(birth: u16, survival: u16) -> String {
+    let digits = |mask: u16| {
+        (0..=8)
+            .filter(|n| mask & (1 << n) != 0)
+            .map(|n| n.to_string())
+            .collect::<String>()
+    };
+
+    format!("B{}/S{}", digits(birth), digits(survival))
 }
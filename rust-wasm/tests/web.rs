@@ -52,3 +52,126 @@ pub fn test_tick() {
 
     assert_eq!(input_universe.get_cells(), expected_universe.get_cells());
 }
+
+/// Tests that `new_with_seed` is deterministic: the same seed always produces the same cells,
+/// and that `seed()` reports it back until the cells are mutated by something other than a
+/// seeded reset.
+#[wasm_bindgen_test]
+pub fn test_seeded_reset_determinism() {
+    let a = Universe::new_with_seed(10, 10, 42);
+    let b = Universe::new_with_seed(10, 10, 42);
+
+    assert_eq!(a.get_cells(), b.get_cells());
+    assert_eq!(a.seed(), Some(42));
+
+    let mut c = Universe::new_with_seed(10, 10, 42);
+    c.toggle_cell(0, 0);
+
+    assert_eq!(c.seed(), None);
+}
+
+/// Tests that `set_rule_string` parses `B.../S...` notation into bit masks that actually
+/// change `tick` behavior (rather than being silently ignored), and that it rejects rule
+/// strings with a digit outside 0-8 or a part that isn't `B.../S...`.
+#[wasm_bindgen_test]
+pub fn test_rule_string_changes_tick_behavior() {
+    // Two adjacent live cells, each with exactly 1 live neighbor.
+    let domino = [(3, 3), (3, 4)];
+
+    // Under the standard B3/S23 rule, underpopulation kills both.
+    let mut default_rule = Universe::new();
+    default_rule.set_width(8);
+    default_rule.set_height(8);
+    default_rule.set_alive_cells(&domino);
+    default_rule.tick();
+
+    let empty = {
+        let mut universe = Universe::new();
+        universe.set_width(8);
+        universe.set_height(8);
+        universe
+    };
+
+    assert_eq!(default_rule.get_cells(), empty.get_cells());
+
+    // Under "B3/S1" (survival at exactly 1 neighbor), both cells survive instead.
+    let mut custom_rule = Universe::new();
+    custom_rule.set_width(8);
+    custom_rule.set_height(8);
+    custom_rule.set_alive_cells(&domino);
+    custom_rule.set_rule_string("B3/S1").unwrap();
+    custom_rule.tick();
+
+    let mut expected = Universe::new();
+    expected.set_width(8);
+    expected.set_height(8);
+    expected.set_alive_cells(&domino);
+
+    assert_eq!(custom_rule.get_cells(), expected.get_cells());
+
+    let mut invalid = Universe::new();
+
+    assert!(invalid.set_rule_string("B9/S23").is_err());
+    assert!(invalid.set_rule_string("X3/S23").is_err());
+}
+
+/// Tests that `render()` (the `Display` impl) and `from_string()` are inverses: rendering a
+/// universe to a `◼`/`◻` glyph grid and loading that string back produces the same cells.
+#[wasm_bindgen_test]
+pub fn test_render_from_string_round_trip() {
+    let universe = input_universe();
+    let rendered = universe.render();
+
+    let loaded = Universe::from_string(6, 6, &rendered);
+
+    assert_eq!(loaded.get_cells(), universe.get_cells());
+}
+
+/// Tests that `to_rle()`/`load_rle()` round-trip a pattern, and that loading onto a grid that
+/// already has unrelated live cells inside the pattern's declared bounding box replaces them
+/// instead of OR-ing the pattern on top.
+#[wasm_bindgen_test]
+pub fn test_rle_round_trip() {
+    let input_universe = input_universe();
+    let rle = input_universe.to_rle();
+
+    let mut loaded = Universe::new();
+    loaded.set_width(6);
+    loaded.set_height(6);
+    loaded.set_alive_cells(&[(0, 0), (5, 5)]);
+
+    loaded.load_rle(&rle).unwrap();
+
+    assert_eq!(loaded.get_cells(), input_universe.get_cells());
+}
+
+/// Tests that `tick_incremental` (via `set_incremental(true)`) matches the full scan over
+/// several generations, for both a still life (block) and an oscillator (blinker), under the
+/// default Game of Life rule.
+#[wasm_bindgen_test]
+pub fn test_incremental_tick_matches_full_tick() {
+    let patterns: [&[(u32, u32)]; 2] = [
+        &[(2, 2), (2, 3), (3, 2), (3, 3)],
+        &[(2, 1), (2, 2), (2, 3)],
+    ];
+
+    for cells in patterns {
+        let mut full = Universe::new();
+        full.set_width(8);
+        full.set_height(8);
+        full.set_alive_cells(cells);
+
+        let mut incremental = Universe::new();
+        incremental.set_width(8);
+        incremental.set_height(8);
+        incremental.set_alive_cells(cells);
+        incremental.set_incremental(true);
+
+        for _ in 0..4 {
+            full.tick();
+            incremental.tick();
+
+            assert_eq!(full.get_cells(), incremental.get_cells());
+        }
+    }
+}